@@ -2,43 +2,118 @@ use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind};
 use rand::Rng;
 use ratatui::{
     buffer::Buffer,
-    layout::{Alignment, Rect},
-    style::{Color, Style, Stylize},
-    symbols::border,
+    layout::{Alignment, Constraint, Flex, Layout, Rect},
+    style::{Color, Stylize},
+    symbols::{border, Marker},
     text::{Line, Text},
-    widgets::{Block, Paragraph, Widget},
+    widgets::{
+        canvas::{Canvas, Rectangle},
+        Block, Clear, Paragraph, Widget,
+    },
     DefaultTerminal, Frame,
 };
 use std::io;
+use std::time::{Duration, Instant};
 
 fn main() -> io::Result<()> {
     let mut terminal = ratatui::init();
-    let app_result = App::default().run(&mut terminal);
+    let size = terminal.size()?;
+    let app_result = App::new(size.width, size.height).run(&mut terminal);
     ratatui::restore();
     app_result
 }
 
+/// fallback play-area bounds for contexts (e.g. tests) without a real terminal size
+const DEFAULT_WIDTH: u16 = 167;
+const DEFAULT_HEIGHT: u16 = 14;
+
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum GameState {
+    #[default]
+    Running,
+    Paused,
+    GameOver,
+}
+
+/// single source of truth for key bindings, driving both input handling and
+/// the instructions/help text so the two can never drift apart
+const KEY_BINDINGS: &[(KeyCode, &str)] = &[
+    (KeyCode::Up, "Move up"),
+    (KeyCode::Down, "Move down"),
+    (KeyCode::Left, "Move left"),
+    (KeyCode::Right, "Move right"),
+    (KeyCode::Char(' '), "Pause"),
+    (KeyCode::Char('r'), "Restart"),
+    (KeyCode::Char('q'), "Quit"),
+];
+
+/// short display form for a key binding, e.g. `↑` or `<Q>`
+fn key_label(code: KeyCode) -> String {
+    match code {
+        KeyCode::Up => "↑".to_string(),
+        KeyCode::Down => "↓".to_string(),
+        KeyCode::Left => "←".to_string(),
+        KeyCode::Right => "→".to_string(),
+        KeyCode::Char(' ') => "<Space>".to_string(),
+        KeyCode::Char(c) => format!("<{}>", c.to_ascii_uppercase()),
+        _ => "?".to_string(),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    fn delta(&self) -> (i16, i16) {
+        match self {
+            Direction::Up => (0, -1),
+            Direction::Down => (0, 1),
+            Direction::Left => (-1, 0),
+            Direction::Right => (1, 0),
+        }
+    }
+
+    fn opposite(&self) -> Direction {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct App {
     score: u8,
     exit: bool,
     snake: Snake,
     apple: Apple,
-    direction: (i16, i16),
+    direction: Direction,
+    next_direction: Direction,
+    width: u16,
+    height: u16,
+    state: GameState,
+    base_tick: Duration,
+    tick_rate: Duration,
 }
 
 #[derive(Debug)]
 pub struct Snake {
     body: Vec<(u16, u16)>,
-    head: String,
     length: u16,
 }
 
-impl Default for Snake {
-    fn default() -> Self {
+impl Snake {
+    /// spawns a single-segment snake centered within a `width` x `height` play area
+    fn new(width: u16, height: u16) -> Self {
         Self {
-            body: vec![(84 as u16, 7 as u16)],
-            head: "X".to_string(),
+            body: vec![(width / 2, height / 2)],
             length: 1,
         }
     }
@@ -47,37 +122,75 @@ impl Default for Snake {
 #[derive(Debug)]
 pub struct Apple {
     position: (u16, u16), // (x, y) x is left and right, y is up and down. generated randomly
-    unit: String,
 }
 
-impl Default for Apple {
-    fn default() -> Self {
+impl Apple {
+    /// places the apple randomly within a `width` x `height` play area
+    fn new(width: u16, height: u16) -> Self {
         let mut rng = rand::thread_rng();
         Self {
-            position: (rng.gen_range(0..167), rng.gen_range(1..14)), // x and y are random
-            unit: "🍎".to_string(),
+            position: (rng.gen_range(0..width), rng.gen_range(1..height)),
         }
     }
 }
 
-impl Default for App {
+impl Default for Apple {
     fn default() -> Self {
+        Self::new(DEFAULT_WIDTH, DEFAULT_HEIGHT)
+    }
+}
+
+/// starting tick interval; shrinks as the score climbs, see [`App::recompute_tick_rate`]
+const BASE_TICK: Duration = Duration::from_millis(150);
+/// tick reduction (in ms) per point scored
+const SPEED_STEP_MS: u64 = 5;
+/// maximum total reduction (in ms) from `BASE_TICK`
+const SPEED_FLOOR_MS: u64 = 100;
+/// fastest the game is allowed to tick, however high the score climbs
+const MIN_TICK: Duration = Duration::from_millis(50);
+
+impl App {
+    /// builds a fresh game for a `width` x `height` play area
+    fn new(width: u16, height: u16) -> Self {
         Self {
             score: 0,
             exit: false,
-            direction: (1, 0),
-            snake: Snake::default(),
-            apple: Apple::default(),
+            direction: Direction::Right,
+            next_direction: Direction::Right,
+            snake: Snake::new(width, height),
+            apple: Apple::new(width, height),
+            width,
+            height,
+            state: GameState::Running,
+            base_tick: BASE_TICK,
+            tick_rate: BASE_TICK,
         }
     }
 }
 
+impl Default for App {
+    fn default() -> Self {
+        Self::new(DEFAULT_WIDTH, DEFAULT_HEIGHT)
+    }
+}
+
 impl App {
-    /// runs the application's main loop until the user quits
+    /// runs the application's main loop until the user quits, advancing the
+    /// snake on a fixed tick while still accepting direction changes in between
     pub fn run(&mut self, terminal: &mut DefaultTerminal) -> io::Result<()> {
+        let mut last_tick = Instant::now();
         while !self.exit {
             terminal.draw(|frame| self.draw(frame))?;
-            self.handle_events()?;
+
+            let timeout = self.tick_rate.saturating_sub(last_tick.elapsed());
+            self.handle_events(timeout)?;
+
+            if last_tick.elapsed() >= self.tick_rate {
+                if self.state == GameState::Running {
+                    self.on_tick();
+                }
+                last_tick = Instant::now();
+            }
         }
         Ok(())
     }
@@ -86,35 +199,81 @@ impl App {
         frame.render_widget(self, frame.area());
     }
 
-    /// updates the application's state based on user input
-    fn handle_events(&mut self) -> io::Result<()> {
-        match event::read()? {
+    /// polls for a key event within `timeout` and applies it, without moving the snake
+    fn handle_events(&mut self, timeout: Duration) -> io::Result<()> {
+        if event::poll(timeout)? {
             // it's important to check that the event is a key press event as
             // crossterm also emits key release and repeat events on Windows.
-            Event::Key(key_event) if key_event.kind == KeyEventKind::Press => {
-                self.handle_key_event(key_event)
+            if let Event::Key(key_event) = event::read()?
+                && key_event.kind == KeyEventKind::Press
+            {
+                self.handle_key_event(key_event);
             }
-            _ => {}
-        };
+        }
         Ok(())
     }
 
     fn handle_key_event(&mut self, key_event: KeyEvent) {
-        match key_event.code {
-            KeyCode::Char('q') => self.exit(),
-            KeyCode::Left => self.direction = (-1, 0),
-            KeyCode::Right => self.direction = (1, 0),
-            KeyCode::Down => self.direction = (0, 1),
-            KeyCode::Up => self.direction = (0, -1),
-            _ => {}
+        // only keys listed in `KEY_BINDINGS` are acted on
+        if !KEY_BINDINGS.iter().any(|(code, _)| *code == key_event.code) {
+            return;
         }
-        
-        let (dx, dy) = self.direction;
-        self.snake.body.insert(0, self.snake.body[0].clone());
-        self.snake.body[0] = (
-            (self.snake.body[0].0 as i16 + dx) as u16,
-            (self.snake.body[0].1 as i16 + dy) as u16,
-        );
+
+        let requested = match key_event.code {
+            KeyCode::Char('q') => {
+                self.exit();
+                return;
+            }
+            KeyCode::Char('r') if self.state == GameState::GameOver => {
+                *self = App::new(self.width, self.height);
+                return;
+            }
+            KeyCode::Char(' ') => {
+                self.state = match self.state {
+                    GameState::Running => GameState::Paused,
+                    GameState::Paused => GameState::Running,
+                    GameState::GameOver => GameState::GameOver,
+                };
+                return;
+            }
+            KeyCode::Left => Some(Direction::Left),
+            KeyCode::Right => Some(Direction::Right),
+            KeyCode::Down => Some(Direction::Down),
+            KeyCode::Up => Some(Direction::Up),
+            _ => None,
+        };
+
+        // ignore a reversal into the snake's own neck; compare against the
+        // still-committed direction (not the queued one) so two presses in
+        // one tick can't chain through an intermediate turn into a reversal
+        if let Some(direction) = requested
+            && direction != self.direction.opposite()
+        {
+            self.next_direction = direction;
+        }
+    }
+
+    /// advances the snake one cell in the current direction, ending the game
+    /// on a wall or self collision
+    fn on_tick(&mut self) {
+        self.direction = self.next_direction;
+        let (dx, dy) = self.direction.delta();
+        let (head_x, head_y) = self.snake.body[0];
+        let new_x = head_x as i16 + dx;
+        let new_y = head_y as i16 + dy;
+
+        if new_x < 0 || new_y < 0 || new_x >= self.width as i16 || new_y >= self.height as i16 {
+            self.state = GameState::GameOver;
+            return;
+        }
+
+        let new_head = (new_x as u16, new_y as u16);
+        if self.snake.body.contains(&new_head) {
+            self.state = GameState::GameOver;
+            return;
+        }
+
+        self.snake.body.insert(0, new_head);
         self.eat_apple();
 
         while self.snake.body.len() > self.snake.length as usize {
@@ -130,66 +289,150 @@ impl App {
         if self.snake.body[0] == self.apple.position {
             self.score += 1;
             self.snake.length += 1;
-            self.apple = Apple::default();
+            self.apple = Apple::new(self.width, self.height);
+            self.recompute_tick_rate();
         }
     }
+
+    /// derives the effective tick rate from the score, clamped to `MIN_TICK`
+    fn recompute_tick_rate(&mut self) {
+        let reduction = (self.score as u64 * SPEED_STEP_MS).min(SPEED_FLOOR_MS);
+        self.tick_rate = self
+            .base_tick
+            .saturating_sub(Duration::from_millis(reduction))
+            .max(MIN_TICK);
+    }
 }
 
 impl Widget for &App {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        let instructions = Line::from(vec![
-            " Move Left ".into(),
-            "⬅️".blue().bold(),
-            " Move Right ".into(),
-            "➡️".blue().bold(),
-            " Move Down ".into(),
-            "⬇️".blue().bold(),
-            " Move Up ".into(),
-            "⬆️".blue().bold(),
-            " Quit ".into(),
-            "<Q> ".blue().bold(),
+        let instructions = Line::from(
+            KEY_BINDINGS
+                .iter()
+                .flat_map(|(code, desc)| {
+                    vec![
+                        format!(" {} ", desc).into(),
+                        format!("{} ", key_label(*code)).blue().bold(),
+                    ]
+                })
+                .collect::<Vec<_>>(),
+        );
+        let title = Line::from(vec![
+            " Score: ".bold(),
+            self.score.to_string().yellow(),
+            "  Speed: ".bold(),
+            format!("{}ms ", self.tick_rate.as_millis()).yellow(),
         ]);
         let block = Block::bordered()
-            .title_top(" Score ".bold())
+            .title_top(title)
             .title_alignment(Alignment::Center)
             .title_bottom(instructions)
             .title_alignment(Alignment::Center)
             .border_set(border::THICK);
 
-        let score_text = Text::from(vec![Line::from(vec![
-            "Value: ".into(),
-            self.score.to_string().yellow(),
-        ])]);
+        let inner = block.inner(area);
+        block.render(area, buf);
 
-        Paragraph::new(score_text)
-            .centered()
-            .block(block)
-            .render(area, buf);
-
-        // draw the snake
-        for (i, p) in self.snake.body.iter().enumerate() {
-            let color: Color = match i {
-                0 => Color::Rgb(255, 0, 0),
-                _ if i % 2 == 0 => Color::Rgb(0, 192, 0),
-                _ => Color::Rgb(255, 128, 0),
-            };
-            buf.set_string(
-                p.0 as u16,
-                p.1 as u16,
-                self.snake.head.clone(),
-                Style::new().fg(color),
-            );
-            // draw the apple
-            buf.set_string(
-                self.apple.position.0 as u16,
-                self.apple.position.1 as u16,
-                &self.apple.unit,
-                Style::new(),
-            );
+        let width = self.width as f64;
+        let height = self.height as f64;
+        Canvas::default()
+            .marker(Marker::Block)
+            .x_bounds([0.0, width])
+            .y_bounds([0.0, height])
+            .paint(|ctx| {
+                // the canvas y-axis grows upward, while game coordinates grow
+                // downward, so flip when plotting
+                for (i, p) in self.snake.body.iter().enumerate() {
+                    let color = match i {
+                        0 => Color::Rgb(255, 0, 0),
+                        _ if i % 2 == 0 => Color::Rgb(0, 192, 0),
+                        _ => Color::Rgb(255, 128, 0),
+                    };
+                    ctx.draw(&Rectangle {
+                        x: p.0 as f64,
+                        y: height - p.1 as f64 - 1.0,
+                        width: 1.0,
+                        height: 1.0,
+                        color,
+                    });
+                }
+
+                ctx.draw(&Rectangle {
+                    x: self.apple.position.0 as f64,
+                    y: height - self.apple.position.1 as f64 - 1.0,
+                    width: 1.0,
+                    height: 1.0,
+                    color: Color::Rgb(220, 20, 60),
+                });
+            })
+            .render(inner, buf);
+
+        match self.state {
+            GameState::GameOver => self.render_game_over(area, buf),
+            GameState::Paused => self.render_pause_overlay(area, buf),
+            GameState::Running => {}
         }
     }
 }
 
+impl App {
+    /// draws a centered overlay listing every binding in `KEY_BINDINGS`
+    fn render_pause_overlay(&self, area: Rect, buf: &mut Buffer) {
+        let mut lines = vec![Line::from("Paused".bold())];
+        lines.extend(
+            KEY_BINDINGS
+                .iter()
+                .map(|(code, desc)| Line::from(format!("{:<8} {}", key_label(*code), desc))),
+        );
+        let text = Text::from(lines);
+
+        let popup = Block::bordered()
+            .title_top(" Help ".bold())
+            .title_alignment(Alignment::Center)
+            .border_set(border::THICK);
+
+        let [popup_area] = Layout::horizontal([Constraint::Length(24)])
+            .flex(Flex::Center)
+            .areas(area);
+        let [popup_area] = Layout::vertical([Constraint::Length(KEY_BINDINGS.len() as u16 + 3)])
+            .flex(Flex::Center)
+            .areas(popup_area);
+
+        Clear.render(popup_area, buf);
+        Paragraph::new(text)
+            .centered()
+            .block(popup)
+            .render(popup_area, buf);
+    }
+
+    /// draws a centered panel announcing the final score and the restart/quit keys
+    fn render_game_over(&self, area: Rect, buf: &mut Buffer) {
+        let text = Text::from(vec![
+            Line::from("Game Over".bold()),
+            Line::from(format!("Final score: {}", self.score)),
+            Line::from("Press <R> to restart / <Q> to quit"),
+        ]);
+
+        let popup = Block::bordered()
+            .title_top(" Game Over ".bold())
+            .title_alignment(Alignment::Center)
+            .border_set(border::THICK);
+
+        let [popup_area] = Layout::horizontal([Constraint::Length(40)])
+            .flex(Flex::Center)
+            .areas(area);
+        let [popup_area] = Layout::vertical([Constraint::Length(5)])
+            .flex(Flex::Center)
+            .areas(popup_area);
+
+        Clear.render(popup_area, buf);
+        Paragraph::new(text)
+            .centered()
+            .block(popup)
+            .render(popup_area, buf);
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -202,4 +445,74 @@ mod tests {
         app.eat_apple();
         assert_eq!(app.score, 1);
     }
+
+    #[test]
+    fn spawns_inside_bounds() {
+        let app = App::new(80, 24);
+        let (x, y) = app.snake.body[0];
+        assert!(x < app.width);
+        assert!(y < app.height);
+    }
+
+    #[test]
+    fn wall_collision_ends_game() {
+        let mut app = App::new(10, 10);
+        app.snake.body = vec![(9, 5)];
+        app.direction = Direction::Right;
+        app.next_direction = Direction::Right;
+        app.on_tick();
+        assert_eq!(app.state, GameState::GameOver);
+    }
+
+    #[test]
+    fn self_collision_ends_game() {
+        let mut app = App::new(20, 20);
+        app.snake.body = vec![(5, 5), (6, 5), (6, 6), (5, 6), (4, 6)];
+        app.snake.length = app.snake.body.len() as u16;
+        app.direction = Direction::Down;
+        app.next_direction = Direction::Down;
+        app.on_tick();
+        assert_eq!(app.state, GameState::GameOver);
+    }
+
+    #[test]
+    fn two_presses_in_one_tick_cannot_chain_into_a_reversal() {
+        use crossterm::event::KeyModifiers;
+
+        let mut app = App::default();
+        assert_eq!(app.direction, Direction::Right);
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE));
+        app.handle_key_event(KeyEvent::new(KeyCode::Left, KeyModifiers::NONE));
+
+        assert_ne!(app.next_direction, Direction::Left);
+    }
+
+    #[test]
+    fn tick_rate_shrinks_as_score_climbs_but_respects_the_floor() {
+        let mut app = App::default();
+        let starting_rate = app.tick_rate;
+
+        app.score = 5;
+        app.recompute_tick_rate();
+        assert!(app.tick_rate < starting_rate);
+
+        app.score = 255;
+        app.recompute_tick_rate();
+        assert_eq!(app.tick_rate, MIN_TICK);
+    }
+
+    #[test]
+    fn space_toggles_pause() {
+        use crossterm::event::KeyModifiers;
+
+        let mut app = App::default();
+        assert_eq!(app.state, GameState::Running);
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Char(' '), KeyModifiers::NONE));
+        assert_eq!(app.state, GameState::Paused);
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Char(' '), KeyModifiers::NONE));
+        assert_eq!(app.state, GameState::Running);
+    }
 }